@@ -2,4 +2,19 @@
 pub enum Error {
     #[fail(display = "{} value is out of range (min: {} - max: {})", _0, _1, _2)]
     OutOfRange(u32, u32, u32),
+
+    #[fail(display = "unexpected character at position {}: expected '{}', found '{}'", _0, _1, _2)]
+    UnexpectedLiteral(usize, char, char),
+
+    #[fail(display = "missing field: {}", _0)]
+    MissingField(&'static str),
+
+    #[fail(display = "week {} is out of range for year {} (max: {})", _0, _1, _2)]
+    WeekOutOfRange(u32, i32, u32),
+
+    #[fail(display = "unknown format specifier '%{}'", _0)]
+    UnknownSpecifier(char),
+
+    #[fail(display = "expected a digit at position {}, found '{}'", _0, _1)]
+    ExpectedDigit(usize, char),
 }
@@ -6,4 +6,6 @@ pub mod error;
 pub mod week;
 
 pub use week::CustomWeek;
-pub use week::WeekSpecification;
\ No newline at end of file
+pub use week::CustomWeekExt;
+pub use week::WeekSpecification;
+pub use week::{RelativeMonth, WeekOfMonth};
\ No newline at end of file
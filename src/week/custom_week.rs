@@ -1,5 +1,8 @@
+use crate::error::Error;
 use crate::week::week_specification::WeekSpecification;
-use chrono::{NaiveDate, Duration};
+use chrono::{Datelike, NaiveDate, Duration};
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
 
 /// Custom week implementation.
 ///
@@ -40,6 +43,56 @@ impl CustomWeek {
         self.week_start
     }
 
+    /// The last day (7th day) of the week.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let week = iso_week.week(NaiveDate::from_ymd(2019, 6, 3));
+    ///
+    /// assert_eq!(NaiveDate::from_ymd(2019, 6, 9), week.last_day());
+    /// ~~~~
+    pub fn last_day(&self) -> NaiveDate {
+        self.week_start + Duration::days(6)
+    }
+
+    /// The inclusive range of dates covered by the week, from `week_start` to `last_day`.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let week = iso_week.week(NaiveDate::from_ymd(2019, 6, 3));
+    ///
+    /// assert!(week.range().contains(&NaiveDate::from_ymd(2019, 6, 5)));
+    /// assert!(!week.range().contains(&NaiveDate::from_ymd(2019, 6, 10)));
+    /// ~~~~
+    pub fn range(&self) -> RangeInclusive<NaiveDate> {
+        self.week_start..=self.last_day()
+    }
+
+    /// The seven dates covered by the week, in order.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let week = iso_week.week(NaiveDate::from_ymd(2019, 6, 3));
+    ///
+    /// let days: Vec<NaiveDate> = week.days().collect();
+    /// assert_eq!(7, days.len());
+    /// assert_eq!(NaiveDate::from_ymd(2019, 6, 3), days[0]);
+    /// assert_eq!(NaiveDate::from_ymd(2019, 6, 9), days[6]);
+    /// ~~~~
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let week_start = self.week_start;
+        (0..7).map(move |offset| week_start + Duration::days(offset))
+    }
+
     pub fn specification(&self) -> WeekSpecification {
         self.specification
     }
@@ -56,10 +109,61 @@ impl CustomWeek {
 
     /// Verify if the given date is in the current week.
     pub fn contains(&self, date: NaiveDate) -> bool {
-        date >= self.week_start && date < (self.week_start + Duration::weeks(1))
+        self.range().contains(&date)
+    }
+
+    /// Compare two weeks built from the same `WeekSpecification`, by `week_start`.
+    ///
+    /// Returns `None` when `self` and `other` do not share the same specification, since weeks
+    /// from different specifications have no natural ordering (this is also why `CustomWeek`
+    /// does not implement `Ord` directly).
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use std::cmp::Ordering;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let sunday_start: WeekSpecification = WeekSpecification::sunday_start();
+    ///
+    /// let week = iso_week.week(NaiveDate::from_ymd(2019, 6, 3));
+    /// let next_week = week.succ();
+    /// let other_spec_week = sunday_start.week(NaiveDate::from_ymd(2019, 6, 3));
+    ///
+    /// assert_eq!(Some(Ordering::Less), week.cmp_same_spec(&next_week));
+    /// assert_eq!(None, week.cmp_same_spec(&other_spec_week));
+    /// ~~~~
+    pub fn cmp_same_spec(&self, other: &CustomWeek) -> Option<Ordering> {
+        if self.specification != other.specification {
+            None
+        } else {
+            Some(self.week_start.cmp(&other.week_start))
+        }
+    }
+
+    /// The week's business days, according to its specification's weekend.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let week = iso_week.week(NaiveDate::from_ymd(2019, 6, 3));
+    ///
+    /// let business_days: Vec<NaiveDate> = week.business_days().collect();
+    /// assert_eq!(5, business_days.len());
+    /// assert_eq!(NaiveDate::from_ymd(2019, 6, 3), business_days[0]);
+    /// assert_eq!(NaiveDate::from_ymd(2019, 6, 7), business_days[4]);
+    /// ~~~~
+    pub fn business_days(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.days().filter(move |date| self.specification.is_business_day(*date))
     }
 
-    /// Very naive week formatting
+    /// Very naive week formatting.
+    ///
+    /// Equivalent to `format_date(self.week_start(), fmt)`: date specifiers are resolved
+    /// against the first day of the week. Use `format_date` directly to format a specific
+    /// day within the week.
     ///
     /// Formatters are inspired by `chrono::format::strftime`.
     ///
@@ -70,6 +174,11 @@ impl CustomWeek {
     /// | `%C`  | `20`     | The week year divided by 100, zero-padded to 2 digits. |
     /// | `%y`  | `01`     | The week year modulo 100, zero-padded to 2 digits.     |
     /// | `%W`  | `27`     | Week number, zero-padded to 2 digits.                  |
+    /// | `%u`  | `3`      | Day number within the week, from the spec's first day (1 to 7). |
+    /// | `%w`  | `2`      | Day number within the week, from the spec's first day (0 to 6). |
+    /// | `%d`  | `05`     | Day of month, zero-padded to 2 digits.                 |
+    /// | `%e`  | ` 5`     | Day of month, space-padded to 2 digits.                |
+    /// | `%%`  | `%`      | A literal `%`.                                         |
     /// |-------|----------|--------------------------------------------------------|
     ///
     /// ~~~~
@@ -81,22 +190,62 @@ impl CustomWeek {
     /// let french_theater_dow_2016_53 = NaiveDate::from_ymd(2017, 1, 3);
     /// let week = french_theater_week.week(french_theater_dow_2016_53);
     ///
-    /// assert_eq!("Year 2016", week.format("Year %Y"));
-    /// assert_eq!("Year 2016", week.format("Year %C%y"));
-    /// assert_eq!("Week 53", week.format("Week %W"));
-    /// assert_eq!("S1653", week.format("S%y%W"));
+    /// assert_eq!("Year 2016", week.format("Year %Y").unwrap());
+    /// assert_eq!("Year 2016", week.format("Year %C%y").unwrap());
+    /// assert_eq!("Week 53", week.format("Week %W").unwrap());
+    /// assert_eq!("S1653", week.format("S%y%W").unwrap());
+    /// assert!(week.format("%Q").is_err());
+    /// ~~~~
+    pub fn format(&self, fmt: &str) -> Result<String, Error> {
+        self.format_date(self.week_start, fmt)
+    }
+
+    /// Format the week, resolving day-of-week and day-of-month specifiers against `date`
+    /// rather than `week_start`.
+    ///
+    /// Scans `fmt` left to right so a literal `%` (via `%%`) and a specifier appearing inside
+    /// literal text cannot collide, unlike a chained string-replace. An unrecognized specifier
+    /// is reported as an error instead of being passed through.
+    ///
     /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let date = NaiveDate::from_ymd(2019, 6, 5);
+    /// let week = iso_week.week(date);
     ///
-    pub fn format(&self, fmt: &str) -> String {
-        let full_year = format!("{:04}", self.year);
-        let y_div_100 = format!("{:02}", self.year / 100);
-        let y_mod_100 = format!("{:02}", self.year % 100);
-        let week = format!("{:02}", self.week);
-
-        fmt
-            .replace("%Y", &full_year)
-            .replace("%C", &y_div_100)
-            .replace("%y", &y_mod_100)
-            .replace("%W", &week)
+    /// assert_eq!("2019-W23-3", week.format_date(date, "%Y-W%W-%u").unwrap());
+    /// assert_eq!("2", week.format_date(date, "%w").unwrap());
+    /// assert_eq!("05", week.format_date(date, "%d").unwrap());
+    /// assert_eq!("%", week.format_date(date, "%%").unwrap());
+    /// assert!(week.format_date(date, "%Q").is_err());
+    /// ~~~~
+    pub fn format_date(&self, date: NaiveDate, fmt: &str) -> Result<String, Error> {
+        let mut result = String::with_capacity(fmt.len());
+        let mut chars = fmt.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('%') => result.push('%'),
+                Some('Y') => result.push_str(&format!("{:04}", self.year)),
+                Some('C') => result.push_str(&format!("{:02}", self.year / 100)),
+                Some('y') => result.push_str(&format!("{:02}", self.year % 100)),
+                Some('W') => result.push_str(&format!("{:02}", self.week)),
+                Some('u') => result.push_str(&self.specification.number_from_first_dow(date.weekday()).to_string()),
+                Some('w') => result.push_str(&self.specification.num_days_from_first_dow(date.weekday()).to_string()),
+                Some('d') => result.push_str(&format!("{:02}", date.day())),
+                Some('e') => result.push_str(&format!("{:2}", date.day())),
+                Some(other) => return Err(Error::UnknownSpecifier(other)),
+                None => return Err(Error::UnknownSpecifier('%')),
+            }
+        }
+
+        Ok(result)
     }
 }
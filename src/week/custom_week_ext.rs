@@ -0,0 +1,43 @@
+use crate::week::custom_week::CustomWeek;
+use crate::week::week_specification::WeekSpecification;
+use chrono::{Datelike, NaiveDate};
+
+/// Extends `NaiveDate` with custom-week computations, mirroring `Datelike::iso_week()` ergonomics.
+///
+/// This is a thin wrapper around `WeekSpecification::week`, provided so the natural call site
+/// is `date.custom_week(spec)` rather than `spec.week(date)`.
+pub trait CustomWeekExt {
+    /// The custom week containing this date, according to `spec`.
+    fn custom_week(&self, spec: WeekSpecification) -> CustomWeek;
+
+    /// The weekday number of this date within `spec`'s week (from 1 to 7).
+    fn custom_weekday_number(&self, spec: WeekSpecification) -> u32;
+}
+
+impl CustomWeekExt for NaiveDate {
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::{WeekSpecification, CustomWeekExt};
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let date = NaiveDate::from_ymd(2019, 6, 3);
+    ///
+    /// assert_eq!(iso_week.week(date), date.custom_week(iso_week));
+    /// ~~~~
+    fn custom_week(&self, spec: WeekSpecification) -> CustomWeek {
+        spec.week(*self)
+    }
+
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::{WeekSpecification, CustomWeekExt};
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    /// let date = NaiveDate::from_ymd(2019, 6, 3);
+    ///
+    /// assert_eq!(1, date.custom_weekday_number(iso_week));
+    /// ~~~~
+    fn custom_weekday_number(&self, spec: WeekSpecification) -> u32 {
+        spec.number_from_first_dow(self.weekday())
+    }
+}
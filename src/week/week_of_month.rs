@@ -0,0 +1,34 @@
+/// Identifies which month a week-of-month value actually belongs to.
+///
+/// A week straddling a month boundary is numbered within a month only if at least
+/// `min_days_in_first_week` of its days fall in that month, otherwise it is numbered within
+/// the adjacent month, so a date near the start or end of a month can resolve to a week of
+/// the adjacent month.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum RelativeMonth {
+    /// The week belongs to the month before the queried date's month.
+    Previous,
+    /// The week belongs to the queried date's month.
+    Current,
+    /// The week belongs to the month after the queried date's month.
+    Next,
+}
+
+/// Week number within a month, together with the month it actually belongs to.
+///
+/// See `WeekSpecification::week_of_month_detailed`.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct WeekOfMonth {
+    pub(crate) week: u32,
+    pub(crate) unit: RelativeMonth,
+}
+
+impl WeekOfMonth {
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    pub fn unit(&self) -> RelativeMonth {
+        self.unit
+    }
+}
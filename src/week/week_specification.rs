@@ -1,21 +1,34 @@
 use crate::error::Error;
 use crate::week::custom_week::CustomWeek;
+use crate::week::week_of_month::{RelativeMonth, WeekOfMonth};
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
+/// Default weekend: Saturday and Sunday.
+const DEFAULT_WEEKEND: [bool; 7] = [false, false, false, false, false, true, true];
+
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub struct WeekSpecification {
     first_day: Weekday,
     min_days_in_first_week: u32,
+    weekend: [bool; 7],
 }
 
 impl WeekSpecification {
     pub fn new(first_day: Weekday, min_days_in_first_week: u32) -> Result<WeekSpecification, Error> {
+        Self::new_with_weekend(first_day, min_days_in_first_week, DEFAULT_WEEKEND)
+    }
+
+    /// Like `new`, but with a custom weekend, given as a `[bool; 7]` indexed by
+    /// `Weekday::num_days_from_monday` (e.g. Middle-Eastern calendars typically use
+    /// Friday/Saturday instead of the default Saturday/Sunday).
+    pub fn new_with_weekend(first_day: Weekday, min_days_in_first_week: u32, weekend: [bool; 7]) -> Result<WeekSpecification, Error> {
         if min_days_in_first_week < 1 || min_days_in_first_week > 7 {
             Err(Error::OutOfRange(min_days_in_first_week, 1, 7))
         } else {
             Ok(WeekSpecification {
                 first_day,
                 min_days_in_first_week,
+                weekend,
             })
         }
     }
@@ -24,6 +37,7 @@ impl WeekSpecification {
         WeekSpecification {
             first_day: Weekday::Sun,
             min_days_in_first_week: 1,
+            weekend: DEFAULT_WEEKEND,
         }
     }
 
@@ -31,6 +45,7 @@ impl WeekSpecification {
         WeekSpecification {
             first_day: Weekday::Mon,
             min_days_in_first_week: 4,
+            weekend: DEFAULT_WEEKEND,
         }
     }
 
@@ -38,6 +53,7 @@ impl WeekSpecification {
         WeekSpecification {
             first_day: Weekday::Wed,
             min_days_in_first_week: 4,
+            weekend: DEFAULT_WEEKEND,
         }
     }
 
@@ -49,6 +65,36 @@ impl WeekSpecification {
         self.min_days_in_first_week
     }
 
+    /// Whether `date` falls on a weekend day for current specification.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    ///
+    /// assert!(iso_week.is_weekend(NaiveDate::from_ymd(2019, 6, 1)));
+    /// assert!(!iso_week.is_weekend(NaiveDate::from_ymd(2019, 6, 3)));
+    /// ~~~~
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend[date.weekday().num_days_from_monday() as usize]
+    }
+
+    /// Whether `date` is a business day (i.e. not a weekend day) for current specification.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    ///
+    /// assert!(!iso_week.is_business_day(NaiveDate::from_ymd(2019, 6, 1)));
+    /// assert!(iso_week.is_business_day(NaiveDate::from_ymd(2019, 6, 3)));
+    /// ~~~~
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date)
+    }
+
     /// Find the first day of week based year for current specification.
     ///
     /// ~~~~
@@ -174,4 +220,207 @@ impl WeekSpecification {
 
         CustomWeek::new(week_year, week_nb, week_start, self.clone())
     }
+
+    /// Compute the week-of-month number for `date`, according to current specification.
+    ///
+    /// This applies the same week-splitting rule as `week`, but relative to the first day of
+    /// the month instead of the first day of the year, giving locale-correct calendar-grid
+    /// numbering (as used e.g. by ICU). A week that does not meet `min_days_in_first_week`
+    /// within `date`'s month is folded into the neighbouring month; use
+    /// `week_of_month_detailed` to find out which month actually owns it.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    ///
+    /// assert_eq!(5, iso_week.week_of_month(NaiveDate::from_ymd(2022, 10, 1)));
+    /// assert_eq!(2, iso_week.week_of_month(NaiveDate::from_ymd(2022, 10, 15)));
+    /// assert_eq!(1, iso_week.week_of_month(NaiveDate::from_ymd(2023, 5, 30)));
+    /// ~~~~
+    pub fn week_of_month(&self, date: NaiveDate) -> u32 {
+        self.week_of_month_detailed(date).week()
+    }
+
+    /// Compute the week-of-month for `date`, together with the month it actually belongs to.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::{WeekSpecification, RelativeMonth};
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    ///
+    /// let previous = iso_week.week_of_month_detailed(NaiveDate::from_ymd(2022, 10, 1));
+    /// assert_eq!(5, previous.week());
+    /// assert_eq!(RelativeMonth::Previous, previous.unit());
+    ///
+    /// let next = iso_week.week_of_month_detailed(NaiveDate::from_ymd(2023, 5, 30));
+    /// assert_eq!(1, next.week());
+    /// assert_eq!(RelativeMonth::Next, next.unit());
+    /// ~~~~
+    pub fn week_of_month_detailed(&self, date: NaiveDate) -> WeekOfMonth {
+        let month_first = date.with_day(1).unwrap();
+        let first_count = 7 - self.num_days_from_first_dow(month_first.weekday());
+        let first_week_is_current = first_count >= self.min_days_in_first_week;
+
+        let day = date.day();
+
+        if day <= first_count {
+            return if first_week_is_current {
+                WeekOfMonth { week: 1, unit: RelativeMonth::Current }
+            } else {
+                let month_last_day = month_first - Duration::days(1);
+                WeekOfMonth {
+                    week: self.week_of_month(month_last_day),
+                    unit: RelativeMonth::Previous,
+                }
+            };
+        }
+
+        let week = ((day - first_count - 1) / 7) + if first_week_is_current { 2 } else { 1 };
+
+        let month_last_day = NaiveDate::from_ymd(date.year(), date.month(), days_in_month(date.year(), date.month()));
+        let last_week_start = month_last_day - Duration::days(self.num_days_from_first_dow(month_last_day.weekday()) as i64);
+        let date_week_start = date - Duration::days(self.num_days_from_first_dow(date.weekday()) as i64);
+
+        if date_week_start == last_week_start {
+            let days_in_tail_week = (month_last_day - last_week_start).num_days() as u32 + 1;
+            if days_in_tail_week < self.min_days_in_first_week {
+                return WeekOfMonth { week: 1, unit: RelativeMonth::Next };
+            }
+        }
+
+        WeekOfMonth { week, unit: RelativeMonth::Current }
+    }
+
+    /// Parse a week identifier back into a `CustomWeek`, the inverse of `CustomWeek::format`.
+    ///
+    /// Recognizes the `%Y`, `%C`, `%y`, `%W` and `%%` specifiers; any other specifier is an
+    /// error, and any other character in `fmt` is matched literally against `input`. The week-year is taken from `%Y` if present, otherwise
+    /// from `%C` and `%y` combined; if only `%y` is given, the 21st century is assumed (`%y`
+    /// alone cannot otherwise be resolved unambiguously).
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::{WeekSpecification, CustomWeek};
+    ///
+    /// let french_theater_week: WeekSpecification = WeekSpecification::french_theater_week();
+    ///
+    /// let expected_week = CustomWeek::new(2016, 53, NaiveDate::from_ymd(2016, 12, 28), french_theater_week.clone());
+    ///
+    /// assert_eq!(expected_week, french_theater_week.parse("S1653", "S%y%W").unwrap());
+    /// assert_eq!(expected_week, french_theater_week.parse("Year 2016 - Week 53", "Year %Y - Week %W").unwrap());
+    /// assert_eq!(expected_week, french_theater_week.parse("2016%W53", "%Y%%W%W").unwrap());
+    /// ~~~~
+    pub fn parse(&self, input: &str, fmt: &str) -> Result<CustomWeek, Error> {
+        let input: Vec<char> = input.chars().collect();
+        let fmt: Vec<char> = fmt.chars().collect();
+
+        let mut pos = 0usize;
+        let mut fmt_idx = 0usize;
+
+        let mut full_year: Option<i32> = None;
+        let mut century: Option<i32> = None;
+        let mut year_mod_100: Option<i32> = None;
+        let mut week: Option<u32> = None;
+
+        while fmt_idx < fmt.len() {
+            let c = fmt[fmt_idx];
+            if c == '%' && fmt_idx + 1 < fmt.len() {
+                let spec = fmt[fmt_idx + 1];
+                fmt_idx += 2;
+                match spec {
+                    '%' => {
+                        let found = *input.get(pos).unwrap_or(&'\0');
+                        if found != '%' {
+                            return Err(Error::UnexpectedLiteral(pos, '%', found));
+                        }
+                        pos += 1;
+                    }
+                    'Y' => full_year = Some(parse_digits(&input, &mut pos, 4)?),
+                    'C' => century = Some(parse_digits(&input, &mut pos, 2)?),
+                    'y' => year_mod_100 = Some(parse_digits(&input, &mut pos, 2)?),
+                    'W' => week = Some(parse_digits(&input, &mut pos, 2)? as u32),
+                    _ => return Err(Error::UnknownSpecifier(spec)),
+                }
+            } else {
+                let found = *input.get(pos).unwrap_or(&'\0');
+                if found != c {
+                    return Err(Error::UnexpectedLiteral(pos, c, found));
+                }
+                pos += 1;
+                fmt_idx += 1;
+            }
+        }
+
+        let year = match (full_year, century, year_mod_100) {
+            (Some(year), _, _) => year,
+            (None, Some(century), Some(year_mod_100)) => century * 100 + year_mod_100,
+            // `%y` alone cannot be resolved unambiguously: assume the 21st century.
+            (None, None, Some(year_mod_100)) => 2000 + year_mod_100,
+            _ => return Err(Error::MissingField("week-year (%Y or %C/%y)")),
+        };
+
+        let week = week.ok_or(Error::MissingField("week number (%W)"))?;
+
+        let num_weeks = self.num_weeks(year);
+        if week < 1 || week > num_weeks {
+            return Err(Error::WeekOutOfRange(week, year, num_weeks));
+        }
+
+        let week_start = self.first_day_of_week_based_year(year) + Duration::weeks((week - 1) as i64);
+
+        Ok(CustomWeek::new(year, week, week_start, *self))
+    }
+
+    /// Yield each successive week from `self.week(start)` to `self.week(end)`, inclusive.
+    ///
+    /// Supports grouping/reporting use cases (e.g. bucketing events by custom week over an
+    /// arbitrary span) without callers hand-rolling `succ` loops and boundary checks. `start`
+    /// must not be after `end`.
+    ///
+    /// ~~~~
+    /// use chrono::NaiveDate;
+    /// use chrono_ext::WeekSpecification;
+    ///
+    /// let iso_week: WeekSpecification = WeekSpecification::iso_week();
+    ///
+    /// let weeks: Vec<u32> = iso_week
+    ///     .weeks_in_range(NaiveDate::from_ymd(2019, 6, 3), NaiveDate::from_ymd(2019, 6, 20))
+    ///     .map(|week| week.week())
+    ///     .collect();
+    ///
+    /// assert_eq!(vec![23, 24, 25], weeks);
+    /// ~~~~
+    pub fn weeks_in_range(&self, start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = CustomWeek> {
+        let last_week_start = self.week(end).week_start();
+
+        std::iter::successors(Some(self.week(start)), move |week| {
+            if week.week_start() < last_week_start {
+                Some(week.succ())
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// Parse `count` decimal digits from `input` starting at `*pos`, advancing `*pos` past them.
+fn parse_digits(input: &[char], pos: &mut usize, count: usize) -> Result<i32, Error> {
+    let mut value = 0i32;
+    for i in 0..count {
+        let c = *input.get(*pos + i).unwrap_or(&'\0');
+        let digit = c.to_digit(10).ok_or_else(|| Error::ExpectedDigit(*pos + i, c))?;
+        value = value * 10 + digit as i32;
+    }
+    *pos += count;
+    Ok(value)
+}
+
+/// Number of days in the given month.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    let next_month_first = NaiveDate::from_ymd(next_year, next_month, 1);
+    (next_month_first - NaiveDate::from_ymd(year, month, 1)).num_days() as u32
 }